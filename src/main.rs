@@ -2,7 +2,9 @@
 use std::cell::Cell;
 use core::fmt::Display;
 use dioxus::prelude::*;
+use gloo_storage::{LocalStorage, Storage};
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use tracing::Level;
 use tracing;
 
@@ -11,20 +13,20 @@ fn main() {
     launch(App);
 }
 
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
 enum CellContent {
     Mine,
     Empty(usize),
 }
 
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
 enum CellVisibility {
     Hidden,
     Revealed,
     Flagged,
 }
 
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
 struct CellStatus{
     content: CellContent,
     status: CellVisibility
@@ -39,7 +41,7 @@ impl CellStatus {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 enum GameState {
     Playing,
     Won,
@@ -64,70 +66,185 @@ impl  GameState {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Coordinate {
     x: usize,
     y: usize,
 }
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Game{
     pub width: usize,
     pub height: usize,
-    pub field: Vec<Vec<CellStatus>>,
+    pub mines: usize,
+    pub field: Vec<CellStatus>,
     pub state: GameState,
+    pub mines_placed: bool,
+    pub flags: usize,
+}
+
+/// `localStorage` key under which a saved in-progress game is kept.
+const SAVE_KEY: &str = "minesweeper-save";
+
+/// Render the board as a labelled ASCII grid: lettered rows down the side,
+/// numeric column headers across the top. Hidden cells print `.`, flagged `F`,
+/// revealed mines `*`, an empty region a space, and other numbers their digit.
+impl Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "  ")?;
+        for x in 0..self.width {
+            write!(f, " {}", x % 10)?;
+        }
+        writeln!(f)?;
+
+        for y in 0..self.height {
+            let label = (b'a' + (y % 26) as u8) as char;
+            write!(f, "{} ", label)?;
+            for x in 0..self.width {
+                let ch = match self.get_cell(&Coordinate{x, y}) {
+                    Some(CellStatus{status: CellVisibility::Hidden, ..}) => '.',
+                    Some(CellStatus{status: CellVisibility::Flagged, ..}) => 'F',
+                    Some(CellStatus{content: CellContent::Mine, ..}) => '*',
+                    Some(CellStatus{content: CellContent::Empty(0), ..}) => ' ',
+                    Some(CellStatus{content: CellContent::Empty(n), ..}) => {
+                        std::char::from_digit(*n as u32, 10).unwrap_or('?')
+                    }
+                    None => '?',
+                };
+                write!(f, " {}", ch)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// `localStorage` key under which the best time for a given board is kept.
+fn best_time_key(width: usize, height: usize, mines: usize) -> String {
+    format!("minesweeper-best-{}x{}-{}", width, height, mines)
+}
+
+/// Best recorded time (in seconds) for the board, if any has been stored.
+fn load_best_time(width: usize, height: usize, mines: usize) -> Option<u32> {
+    LocalStorage::get(best_time_key(width, height, mines)).ok()
+}
+
+/// Persist `secs` as the best time for the board, overwriting any previous one.
+fn save_best_time(width: usize, height: usize, mines: usize, secs: u32) {
+    let _ = LocalStorage::set(best_time_key(width, height, mines), secs);
 }
 
 impl Game {
-    fn new(width: usize, height: usize, mines: usize) -> Game {
-        let mut game = Game {
+    /// Build a fresh board. Returns an error instead of producing a game whose
+    /// mine-placement loop could never terminate: the board must have at least
+    /// one cell and leave at least one mine-free cell (`mines < width * height`).
+    fn new(width: usize, height: usize, mines: usize) -> Result<Game, String> {
+        if width == 0 || height == 0 {
+            return Err("Width and height must both be at least 1.".to_string());
+        }
+        if mines >= width * height {
+            return Err(format!(
+                "Too many mines: {} does not fit in a {}x{} board ({} cells).",
+                mines, width, height, width * height
+            ));
+        }
+
+        Ok(Game {
             width: width,
             height: height,
-            field: vec![vec![CellStatus::new(); width]; height],
-            state: GameState::Playing
-        };
+            mines: mines,
+            field: vec![CellStatus::new(); width * height],
+            state: GameState::Playing,
+            mines_placed: false,
+            flags: 0,
+        })
+    }
+
+    /// Mines left to find according to the flags the player has placed. May go
+    /// negative if the player over-flags, mirroring the classic counter.
+    fn mines_remaining(&self) -> isize {
+        self.mines as isize - self.flags as isize
+    }
+
+    fn index(&self, coord: &Coordinate) -> usize {
+        coord.y * self.width + coord.x
+    }
+
+    fn at(&self, idx: usize) -> Coordinate {
+        Coordinate{x: idx % self.width, y: idx / self.width}
+    }
+
+    fn place_mines(&mut self, excluding: &Coordinate) {
+        // Keep the clicked cell and its eight neighbours mine-free so the first
+        // reveal always opens a region instead of ending the game.
+        let mut safe: Vec<Coordinate> = std::iter::once(Coordinate{x: excluding.x, y: excluding.y})
+            .chain(self.get_neighbours(excluding).filter(|c| c != excluding))
+            .collect();
+
+        // On boards too dense to spare the whole safe region, keep only the
+        // clicked cell excluded so the placement loop still terminates.
+        if self.mines + safe.len() > self.width * self.height {
+            safe.truncate(1);
+        }
 
         let mut rng = rand::thread_rng();
 
         let mut placed_bombs: usize = 0;
 
         loop {
-            let x = rng.gen_range(0..width);
-            let y = rng.gen_range(0..height);
-            
-            if let CellContent::Empty(_) = game.field[y][x].content {
-                game.field[y][x] = CellStatus{content: CellContent::Mine, status: CellVisibility::Hidden};
+            if placed_bombs >= self.mines {
+                break;
+            }
+            let x = rng.gen_range(0..self.width);
+            let y = rng.gen_range(0..self.height);
+
+            if safe.iter().any(|c| c.x == x && c.y == y) {
+                continue;
+            }
+
+            let idx = self.index(&Coordinate{x, y});
+            if let CellContent::Empty(_) = self.field[idx].content {
+                self.field[idx] = CellStatus{content: CellContent::Mine, status: CellVisibility::Hidden};
                 placed_bombs += 1;
             }
-            if placed_bombs >= mines {
+            if placed_bombs >= self.mines {
                 break;
             }
         }
 
-        for x in 0..width {
-            for y in 0..height {
+        for x in 0..self.width {
+            for y in 0..self.height {
                 let coord = Coordinate{x, y};
-                if let Some(CellStatus{content: CellContent::Empty(_),status: _}) = game.get_cell(&coord){
+                if let Some(CellStatus{content: CellContent::Empty(_),status: _}) = self.get_cell(&coord){
                     let mut mine_count = 0;
-                    game.get_neighbours(&coord).for_each(|n| {
-                        if let Some(CellStatus{content: CellContent::Mine, status: _}) = game.get_cell(&n) {
+                    self.get_neighbours(&coord).for_each(|n| {
+                        if let Some(CellStatus{content: CellContent::Mine, status: _}) = self.get_cell(&n) {
                             mine_count += 1;
                         }
                     });
 
-                    if let Some(CellStatus{content: CellContent::Empty(n), status: _}) = game.get_cell_mut(&coord) {
+                    if let Some(CellStatus{content: CellContent::Empty(n), status: _}) = self.get_cell_mut(&coord) {
                         *n = mine_count;
                     }
                 }
             }
         };
 
-        game
+        self.mines_placed = true;
     }
 
     fn get_cell(&self, coord: &Coordinate) -> Option<&CellStatus> {
-        self.field.get(coord.y).and_then(|row| row.get(coord.x))
+        if coord.x >= self.width || coord.y >= self.height {
+            return None;
+        }
+        self.field.get(self.index(coord))
     }
 
     fn get_cell_mut(&mut self, coord: &Coordinate) -> Option<&mut CellStatus> {
-        self.field.get_mut(coord.y).and_then(|row| row.get_mut(coord.x))
+        if coord.x >= self.width || coord.y >= self.height {
+            return None;
+        }
+        let idx = self.index(coord);
+        self.field.get_mut(idx)
     }
     
 
@@ -149,31 +266,59 @@ impl Game {
     fn reveal_field_checked(&mut self, coord: Coordinate) {
         if ! self.state.is_playing() {return;}
 
-        if let Some(cell) = self.get_cell_mut(&coord) {
-            let cell_clone = cell.clone();
-            if cell.status == CellVisibility::Hidden {
-                cell.status = CellVisibility::Revealed;
-                if let CellContent::Empty(0) = cell.content {
-                    self.get_neighbours(&coord).collect::<Vec<_>>().into_iter().for_each(|n| {
-                        self.reveal_field_checked(n);
-                    });
+        if ! self.mines_placed {
+            self.place_mines(&coord);
+        }
+
+        match self.get_cell(&coord) {
+            Some(cell) if cell.status == CellVisibility::Hidden => {}
+            _ => return,
+        }
+
+        // Explicit worklist flood fill: reveal the start cell, and for every
+        // revealed `Empty(0)` cell push its still-hidden neighbours' indices
+        // onto a reusable stack. This avoids both recursion depth and the
+        // per-cell neighbour allocation of the old recursive version.
+        let mut stack: Vec<usize> = vec![self.index(&coord)];
+        let mut hit_mine = false;
+        while let Some(idx) = stack.pop() {
+            let content = match self.field.get_mut(idx) {
+                Some(cell) if cell.status == CellVisibility::Hidden => {
+                    cell.status = CellVisibility::Revealed;
+                    cell.content
                 }
-                if cell_clone.content == CellContent::Mine {
-                    self.state = GameState::Lost;
-                } else if self.is_fully_revealed_and_marked() {
-                    self.state = GameState::Won;
+                _ => continue,
+            };
+
+            if content == CellContent::Mine {
+                hit_mine = true;
+            } else if let CellContent::Empty(0) = content {
+                let c = self.at(idx);
+                for n in self.get_neighbours(&c) {
+                    let nidx = self.index(&n);
+                    if let Some(CellStatus{status: CellVisibility::Hidden, ..}) = self.field.get(nidx) {
+                        stack.push(nidx);
+                    }
                 }
             }
         }
-    }   
+
+        if hit_mine {
+            self.state = GameState::Lost;
+        } else if self.is_fully_revealed_and_marked() {
+            self.state = GameState::Won;
+        }
+    }
 
     fn toggle_flag_checked(&mut self, coor: Coordinate) {
         if ! self.state.is_playing() {return;}
         if let Some(cell) = self.get_cell_mut(&coor) {
             if cell.status == CellVisibility::Hidden {
                 cell.status = CellVisibility::Flagged;
+                self.flags += 1;
             } else if cell.status == CellVisibility::Flagged {
                 cell.status = CellVisibility::Hidden;
+                self.flags -= 1;
             }
             if cell.content == CellContent::Mine && self.is_fully_revealed_and_marked() {
                 self.state = GameState::Won;
@@ -182,28 +327,146 @@ impl Game {
         
     }
 
-    fn is_lost(&mut self) -> bool {
+    /// Run naked-single constraint propagation plus a subset rule across the
+    /// board until a full pass makes no change, returning the hidden cells that
+    /// can be proven safe and the hidden cells that can be proven to hold mines.
+    fn deduce(&self) -> (Vec<Coordinate>, Vec<Coordinate>) {
+        let mut known_safe: Vec<Coordinate> = Vec::new();
+        let mut known_mine: Vec<Coordinate> = Vec::new();
+
+        // Flagged cells are taken as known mines from the start.
         for x in 0..self.width {
             for y in 0..self.height {
                 let coord = Coordinate{x, y};
-                if let Some(CellStatus{content: CellContent::Mine, status: CellVisibility::Revealed}) = self.get_cell(&coord) {
-                    return true;
+                if let Some(CellStatus{status: CellVisibility::Flagged, ..}) = self.get_cell(&coord) {
+                    known_mine.push(coord);
+                }
+            }
+        }
+
+        loop {
+            let mut changed = false;
+            let mut constraints: Vec<(Vec<Coordinate>, usize)> = Vec::new();
+
+            for x in 0..self.width {
+                for y in 0..self.height {
+                    let coord = Coordinate{x, y};
+                    let n = match self.get_cell(&coord) {
+                        Some(CellStatus{content: CellContent::Empty(n), status: CellVisibility::Revealed}) => *n,
+                        _ => continue,
+                    };
+
+                    // Partition the neighbours into the remaining mine count and
+                    // the still-undetermined hidden set `H`.
+                    let mut remaining = n as isize;
+                    let mut hidden: Vec<Coordinate> = Vec::new();
+                    for nb in self.get_neighbours(&coord) {
+                        if known_mine.contains(&nb) {
+                            remaining -= 1;
+                        } else if known_safe.contains(&nb) {
+                            // Already proven safe, nothing to decide.
+                        } else if let Some(CellStatus{status: CellVisibility::Hidden, ..}) = self.get_cell(&nb) {
+                            hidden.push(nb);
+                        }
+                    }
+
+                    if hidden.is_empty() || remaining < 0 {
+                        continue;
+                    }
+
+                    if remaining == 0 {
+                        for c in &hidden {
+                            if !known_safe.contains(c) { known_safe.push(*c); changed = true; }
+                        }
+                    } else if remaining as usize == hidden.len() {
+                        for c in &hidden {
+                            if !known_mine.contains(c) { known_mine.push(*c); changed = true; }
+                        }
+                    } else {
+                        constraints.push((hidden, remaining as usize));
+                    }
                 }
             }
+
+            // Subset rule: when one constraint's hidden set is a strict subset
+            // of another's, the difference of the cells holds the difference of
+            // the mine counts, which often forces new safe or mined cells.
+            for i in 0..constraints.len() {
+                for j in 0..constraints.len() {
+                    if i == j { continue; }
+                    let (a_cells, a_rem) = (&constraints[i].0, constraints[i].1);
+                    let (b_cells, b_rem) = (&constraints[j].0, constraints[j].1);
+                    if a_cells.len() >= b_cells.len() || b_rem < a_rem { continue; }
+                    if !a_cells.iter().all(|c| b_cells.contains(c)) { continue; }
+
+                    let diff_cells: Vec<Coordinate> =
+                        b_cells.iter().filter(|c| !a_cells.contains(c)).copied().collect();
+                    let diff_rem = b_rem - a_rem;
+                    if diff_cells.is_empty() { continue; }
+
+                    if diff_rem == 0 {
+                        for c in &diff_cells {
+                            if !known_safe.contains(c) { known_safe.push(*c); changed = true; }
+                        }
+                    } else if diff_rem == diff_cells.len() {
+                        for c in &diff_cells {
+                            if !known_mine.contains(c) { known_mine.push(*c); changed = true; }
+                        }
+                    }
+                }
+            }
+
+            if !changed { break; }
         }
-        return false;
+
+        // Report only the mines we deduced, not the ones the player already flagged.
+        let deduced_mine: Vec<Coordinate> = known_mine.into_iter().filter(|c| {
+            !matches!(self.get_cell(c), Some(CellStatus{status: CellVisibility::Flagged, ..}))
+        }).collect();
+
+        (known_safe, deduced_mine)
     }
 
-    fn is_fully_revealed_and_marked(&mut self) -> bool {
-        for x in 0..self.width {
-            for y in 0..self.height {
-                let coord = Coordinate{x, y};
-                if let Some(CellStatus{content: _, status: CellVisibility::Hidden}) = self.get_cell(&coord) {
-                    return false;
+    /// Return one provably-safe hidden cell, if the position allows a
+    /// deduction; `None` means the position requires guessing.
+    fn hint(&self) -> Option<Coordinate> {
+        self.deduce().0.into_iter().next()
+    }
+
+    /// Apply every certain deduction once: flag all provably-mined cells and
+    /// reveal all provably-safe cells. Returns the coordinates that were acted
+    /// on so the UI can flash them; an empty result means the position requires
+    /// guessing.
+    fn auto_step(&mut self) -> Vec<Coordinate> {
+        let (safe, mines) = self.deduce();
+        let mut touched: Vec<Coordinate> = Vec::new();
+
+        for c in &mines {
+            if let Some(cell) = self.get_cell_mut(c) {
+                if cell.status == CellVisibility::Hidden {
+                    cell.status = CellVisibility::Flagged;
+                    self.flags += 1;
+                    touched.push(*c);
                 }
             }
         }
-        return true;
+        for c in &safe {
+            if let Some(CellStatus{status: CellVisibility::Hidden, ..}) = self.get_cell(c) {
+                self.reveal_field_checked(Coordinate{x: c.x, y: c.y});
+                touched.push(*c);
+            }
+        }
+        touched
+    }
+
+    fn is_lost(&mut self) -> bool {
+        self.field.iter().any(|cell| {
+            matches!(cell, CellStatus{content: CellContent::Mine, status: CellVisibility::Revealed})
+        })
+    }
+
+    fn is_fully_revealed_and_marked(&mut self) -> bool {
+        !self.field.iter().any(|cell| cell.status == CellVisibility::Hidden)
     }
 
 
@@ -213,7 +476,43 @@ impl Game {
 
 #[component]
 fn App() -> Element {
-    let mut game = use_signal(|| Game::new(10, 10, 10));
+    let mut game = use_signal(|| Game::new(10, 10, 10).expect("default board is valid"));
+    let mut hinted = use_signal(Vec::<Coordinate>::new);
+    let mut elapsed = use_signal(|| 0u32);
+    let mut custom_width = use_signal(|| "16".to_string());
+    let mut custom_height = use_signal(|| "16".to_string());
+    let mut custom_mines = use_signal(|| "40".to_string());
+    let mut custom_error = use_signal(String::new);
+
+    // Advance the timer once per second while a game is running. It starts when
+    // the mines are placed (the first reveal) and freezes once the game ends.
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(1000).await;
+            let running = {
+                let g = game.read();
+                g.mines_placed && g.state.is_playing()
+            };
+            if running {
+                elapsed += 1;
+            }
+        }
+    });
+
+    // On a win, record the elapsed time as the board's best if it beats the
+    // stored record.
+    use_effect(move || {
+        if game.read().state == GameState::Won {
+            let (width, height, mines) = {
+                let g = game.read();
+                (g.width, g.height, g.mines)
+            };
+            let secs = elapsed();
+            if load_best_time(width, height, mines).map_or(true, |best| secs < best) {
+                save_best_time(width, height, mines, secs);
+            }
+        }
+    });
 
     rsx! {
         link{rel:"stylesheet", href: "main.css"}
@@ -231,8 +530,19 @@ fn App() -> Element {
                    GameState::Lost => "You lost! ðŸ˜¢",
                }
             },
+            div {
+                class: "status-bar",
+                span {
+                    class: "mines-remaining",
+                    "ðŸ’£ {game.read().mines_remaining()}"
+                }
+                span {
+                    class: "timer",
+                    "â± {elapsed()}"
+                }
+            },
             table {
-                for (y, row) in game.read().field.iter().enumerate() {
+                for (y, row) in game.read().field.chunks(game.read().width).enumerate() {
                     tr{
                         class: "row",
                         for (x, cell) in row.iter().enumerate() {
@@ -243,9 +553,11 @@ fn App() -> Element {
                                 class: if cell.status == CellVisibility::Revealed {"revealed"},
                                 class: if cell.content == CellContent::Mine {"mine"},
                                 class: if let CellContent::Empty(n) = cell.content {format!("empty-{}",n)},
+                                class: if hinted.read().contains(&Coordinate{x, y}) {"hint"},
                                 prevent_default: "oncontextmenu",
                                 onclick: move |e: Event<MouseData>| {
                                     println!("clicked cell {:?}", e);
+                                    hinted.set(Vec::new());
                                     game.with_mut(
                                         |g| {
                                             g.reveal_field_checked(Coordinate{x, y});
@@ -287,16 +599,40 @@ fn App() -> Element {
                 style: "margin-top: 1em;",
                 button {
                     onclick: move |e| {
+                        let cell = game.read().hint();
+                        match cell {
+                            Some(c) => hinted.set(vec![c]),
+                            None => hinted.set(Vec::new()),
+                        }
+                    },
+                    "Hint"
+                }
+                button {
+                    onclick: move |e| {
+                        let touched = game.with_mut(|g| g.auto_step());
+                        hinted.set(touched);
+                    },
+                    "Auto-step"
+                }
+            }
+            div{
+                style: "margin-top: 1em;",
+                button {
+                    onclick: move |e| {
+                        hinted.set(Vec::new());
+                        elapsed.set(0);
                         game.with_mut(|g| {
-                            *g = Game::new(9, 9, 10);
+                            *g = Game::new(9, 9, 10).expect("built-in difficulty is valid");
                         });
                     },
                     "Start a new easy game"
                 }
                 button {
                     onclick: move |e| {
+                        hinted.set(Vec::new());
+                        elapsed.set(0);
                         game.with_mut(|g| {
-                            *g = Game::new(16, 16, 40);
+                            *g = Game::new(16, 16, 40).expect("built-in difficulty is valid");
                         });
                     },
                     "Start a new medium game"
@@ -304,13 +640,113 @@ fn App() -> Element {
                 }
                 button {
                     onclick: move |e| {
+                        hinted.set(Vec::new());
+                        elapsed.set(0);
                         game.with_mut(|g| {
-                            *g = Game::new(30, 16, 99);
+                            *g = Game::new(30, 16, 99).expect("built-in difficulty is valid");
                         });
                     },
                     "Start a new hard game"
                 }
             }
+            div{
+                class: "custom-game",
+                style: "margin-top: 1em;",
+                label { "Width " }
+                input {
+                    r#type: "number",
+                    value: "{custom_width}",
+                    oninput: move |e| custom_width.set(e.value()),
+                }
+                label { " Height " }
+                input {
+                    r#type: "number",
+                    value: "{custom_height}",
+                    oninput: move |e| custom_height.set(e.value()),
+                }
+                label { " Mines " }
+                input {
+                    r#type: "number",
+                    value: "{custom_mines}",
+                    oninput: move |e| custom_mines.set(e.value()),
+                }
+                button {
+                    onclick: move |e| {
+                        let width = custom_width.read().trim().parse::<usize>();
+                        let height = custom_height.read().trim().parse::<usize>();
+                        let mines = custom_mines.read().trim().parse::<usize>();
+                        match (width, height, mines) {
+                            (Ok(w), Ok(h), Ok(m)) => match Game::new(w, h, m) {
+                                Ok(ng) => {
+                                    // Warn when the board is so dense it is barely playable,
+                                    // but still start it.
+                                    if m * 4 >= w * h * 3 {
+                                        custom_error.set("Warning: very high mine density \u{2014} the board may be unplayable.".to_string());
+                                    } else {
+                                        custom_error.set(String::new());
+                                    }
+                                    hinted.set(Vec::new());
+                                    elapsed.set(0);
+                                    game.set(ng);
+                                }
+                                Err(msg) => custom_error.set(msg),
+                            },
+                            _ => custom_error.set("Width, height, and mines must all be numbers.".to_string()),
+                        }
+                    },
+                    "Start custom game"
+                }
+                if !custom_error.read().is_empty() {
+                    p {
+                        class: "custom-error",
+                        "{custom_error}"
+                    }
+                }
+            }
+            div{
+                style: "margin-top: 1em;",
+                button {
+                    onclick: move |e| {
+                        let _ = LocalStorage::set(SAVE_KEY, &*game.read());
+                    },
+                    "Save"
+                }
+                button {
+                    onclick: move |e| {
+                        if let Ok(loaded) = LocalStorage::get::<Game>(SAVE_KEY) {
+                            hinted.set(Vec::new());
+                            elapsed.set(0);
+                            game.set(loaded);
+                        }
+                    },
+                    "Load"
+                }
+            }
+            pre {
+                class: "board-export",
+                "{game.read()}"
+            }
+            div {
+                class: "best-scores",
+                h3 { "Best scores" }
+                BestScore { label: "Easy", width: 9, height: 9, mines: 10 }
+                BestScore { label: "Medium", width: 16, height: 16, mines: 40 }
+                BestScore { label: "Hard", width: 30, height: 16, mines: 99 }
+            }
+        }
+    }
+    }
+
+#[component]
+fn BestScore(label: &'static str, width: usize, height: usize, mines: usize) -> Element {
+    let best = load_best_time(width, height, mines);
+    rsx! {
+        p {
+            class: "best-score",
+            match best {
+                Some(secs) => format!("{label}: {secs}s"),
+                None => format!("{label}: â€”"),
+            }
         }
     }
     }